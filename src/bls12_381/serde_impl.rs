@@ -1,11 +1,19 @@
+extern crate zeroize;
+
 use super::{Fq, FqRepr, Fr, FrRepr, G1, G1Affine, G2, G2Affine};
 use {CurveAffine, CurveProjective, EncodedPoint, PrimeField};
 
-use serde::de::Error as DeserializeError;
+use std::fmt;
+
+use serde::de::{Error as DeserializeError, SeqAccess, Visitor};
+use serde::ser::SerializeTuple;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use zeroize::Zeroize;
 
 const ERR_LEN: &str = "wrong length of deserialized group element";
 const ERR_CODE: &str = "deserialized bytes don't encode a group element";
+const ERR_HEX: &str = "invalid hex string";
+const ERR_SORT: &str = "sort flag must be unset in uncompressed encoding";
 
 impl Serialize for G1 {
     fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
@@ -55,14 +63,118 @@ impl<'de> Deserialize<'de> for G2Affine {
     }
 }
 
-/// Serializes a group element using its compressed representation.
+/// Wraps a group element so it `(de)serializes` via the standard Zcash BLS12-381 uncompressed
+/// encoding instead of the compressed one used by the element's own `Serialize`/`Deserialize`
+/// impls. Compressed G1/G2 are 48/96 bytes; uncompressed are 96/192 bytes (for G2, the two `Fq2`
+/// components are encoded c1-then-c0). In both encodings the three most-significant bits of the
+/// first byte are metadata — 0x80 compression flag, 0x40 infinity flag, 0x20 sort-of-y flag —
+/// followed by the big-endian x-coordinate; here the compression flag is always unset and the
+/// sort flag must be zero. Point at infinity is encoded with the infinity bit set and all
+/// coordinate bits zero.
+#[derive(Debug, PartialEq)]
+pub struct Uncompressed<T>(pub T);
+
+impl Serialize for Uncompressed<G1> {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        serialize_uncompressed(&self.0.into_affine(), s)
+    }
+}
+
+impl<'de> Deserialize<'de> for Uncompressed<G1> {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let affine: G1Affine = deserialize_uncompressed(d)?;
+        Ok(Uncompressed(affine.into_projective()))
+    }
+}
+
+impl Serialize for Uncompressed<G1Affine> {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        serialize_uncompressed(&self.0, s)
+    }
+}
+
+impl<'de> Deserialize<'de> for Uncompressed<G1Affine> {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        Ok(Uncompressed(deserialize_uncompressed(d)?))
+    }
+}
+
+impl Serialize for Uncompressed<G2> {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        serialize_uncompressed(&self.0.into_affine(), s)
+    }
+}
+
+impl<'de> Deserialize<'de> for Uncompressed<G2> {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let affine: G2Affine = deserialize_uncompressed(d)?;
+        Ok(Uncompressed(affine.into_projective()))
+    }
+}
+
+impl Serialize for Uncompressed<G2Affine> {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        serialize_uncompressed(&self.0, s)
+    }
+}
+
+impl<'de> Deserialize<'de> for Uncompressed<G2Affine> {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        Ok(Uncompressed(deserialize_uncompressed(d)?))
+    }
+}
+
+/// Serializes a group element using its uncompressed representation, hex-encoded for
+/// human-readable formats.
+fn serialize_uncompressed<S: Serializer, C: CurveAffine>(c: &C, s: S) -> Result<S::Ok, S::Error> {
+    let uncompressed = c.into_uncompressed();
+    if s.is_human_readable() {
+        s.serialize_str(&encode_hex(uncompressed.as_ref()))
+    } else {
+        s.serialize_bytes(uncompressed.as_ref())
+    }
+}
+
+/// Deserializes the uncompressed representation of a group element, validating the flag bits
+/// and subgroup membership.
+fn deserialize_uncompressed<'de, D: Deserializer<'de>, C: CurveAffine>(
+    d: D,
+) -> Result<C, D::Error> {
+    let bytes = if d.is_human_readable() {
+        d.deserialize_str(HexVisitor)?
+    } else {
+        d.deserialize_bytes(BytesVisitor)?
+    };
+    if bytes.len() != C::Uncompressed::size() {
+        return Err(D::Error::custom(ERR_LEN));
+    }
+    if bytes[0] & 0x20 != 0 {
+        return Err(D::Error::custom(ERR_SORT));
+    }
+    let mut uncompressed = C::Uncompressed::empty();
+    uncompressed.as_mut().copy_from_slice(&bytes);
+    let to_err = |_| D::Error::custom(ERR_CODE);
+    Ok(uncompressed.into_affine().map_err(to_err)?)
+}
+
+/// Serializes a group element using its compressed representation: a lowercase hex string for
+/// human-readable formats (JSON, YAML, ...), or raw bytes for compact binary formats.
 fn serialize_affine<S: Serializer, C: CurveAffine>(c: &C, s: S) -> Result<S::Ok, S::Error> {
-    c.into_compressed().as_ref().serialize(s)
+    let compressed = c.into_compressed();
+    if s.is_human_readable() {
+        s.serialize_str(&encode_hex(compressed.as_ref()))
+    } else {
+        s.serialize_bytes(compressed.as_ref())
+    }
 }
 
 /// Deserializes the compressed representation of a group element.
 fn deserialize_affine<'de, D: Deserializer<'de>, C: CurveAffine>(d: D) -> Result<C, D::Error> {
-    let bytes = <Vec<u8>>::deserialize(d)?;
+    let bytes = if d.is_human_readable() {
+        d.deserialize_str(HexVisitor)?
+    } else {
+        d.deserialize_bytes(BytesVisitor)?
+    };
     if bytes.len() != C::Compressed::size() {
         return Err(D::Error::custom(ERR_LEN));
     }
@@ -72,6 +184,106 @@ fn deserialize_affine<'de, D: Deserializer<'de>, C: CurveAffine>(d: D) -> Result
     Ok(compressed.into_affine().map_err(to_err)?)
 }
 
+/// Visitor accepting a lowercase (or mixed-case) hex string and producing the decoded bytes.
+struct HexVisitor;
+
+impl<'de> Visitor<'de> for HexVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a hex string")
+    }
+
+    fn visit_str<E: DeserializeError>(self, v: &str) -> Result<Vec<u8>, E> {
+        decode_hex(v).map_err(E::custom)
+    }
+}
+
+/// Visitor accepting a byte buffer, borrowed or owned, for binary formats.
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a byte string")
+    }
+
+    fn visit_bytes<E: DeserializeError>(self, v: &[u8]) -> Result<Vec<u8>, E> {
+        Ok(v.to_vec())
+    }
+
+    fn visit_byte_buf<E: DeserializeError>(self, v: Vec<u8>) -> Result<Vec<u8>, E> {
+        Ok(v)
+    }
+}
+
+/// Encodes `bytes` as a lowercase hex string.
+fn encode_hex(bytes: &[u8]) -> String {
+    const HEX_CHARS: &[u8; 16] = b"0123456789abcdef";
+    let mut s = String::with_capacity(bytes.len() * 2);
+    for b in bytes {
+        s.push(HEX_CHARS[(b >> 4) as usize] as char);
+        s.push(HEX_CHARS[(b & 0xf) as usize] as char);
+    }
+    s
+}
+
+/// Decodes a hex string, rejecting odd lengths and non-hex digits.
+fn decode_hex(s: &str) -> Result<Vec<u8>, &'static str> {
+    if s.len() % 2 != 0 {
+        return Err(ERR_HEX);
+    }
+    fn digit(c: u8) -> Result<u8, &'static str> {
+        match c {
+            b'0'..=b'9' => Ok(c - b'0'),
+            b'a'..=b'f' => Ok(c - b'a' + 10),
+            b'A'..=b'F' => Ok(c - b'A' + 10),
+            _ => Err(ERR_HEX),
+        }
+    }
+    s.as_bytes()
+        .chunks(2)
+        .map(|pair| Ok((digit(pair[0])? << 4) | digit(pair[1])?))
+        .collect()
+}
+
+/// Wraps an `Fr` value that is secret key material, as an explicit opt-in to serializing it.
+/// `Fr`'s own `Serialize`/`Deserialize` impls stay available for non-secret scalars (e.g. public
+/// verification values derived from a key), but secret scalars should only ever be serialized
+/// through `SerdeSecret`, so that grepping a codebase for `SerdeSecret` finds every place secret
+/// key material can leave the process.
+///
+/// When serializing, `SerdeSecret` zeroizes the repr limbs and the big-endian byte buffer it
+/// builds, for both human-readable and binary formats; the hex string it hands to a
+/// human-readable `Serializer` is not zeroized. Deserializing goes through `Fr`'s own impl,
+/// which zeroizes its repr limbs and decoded byte buffer. Nothing beyond those buffers is
+/// zeroized: the serialized data still contains the secret wherever the
+/// `Serializer`/`Deserializer` keeps it. Serializing through plain `Fr`/`FrRepr` zeroizes
+/// nothing.
+pub struct SerdeSecret<T>(pub T);
+
+impl Serialize for SerdeSecret<Fr> {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        let mut repr = self.0.into_repr();
+        let mut bytes = repr_to_be_bytes(&repr.0);
+        let result = if s.is_human_readable() {
+            s.serialize_str(&encode_hex(&bytes))
+        } else {
+            serialize_fixed_bytes(&bytes, s)
+        };
+        bytes.zeroize();
+        repr.0.zeroize();
+        result
+    }
+}
+
+impl<'de> Deserialize<'de> for SerdeSecret<Fr> {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        Ok(SerdeSecret(Fr::deserialize(d)?))
+    }
+}
+
 impl Serialize for Fr {
     fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
         self.into_repr().serialize(s)
@@ -80,19 +292,35 @@ impl Serialize for Fr {
 
 impl<'de> Deserialize<'de> for Fr {
     fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
-        Fr::from_repr(FrRepr::deserialize(d)?).map_err(|_| D::Error::custom(ERR_CODE))
+        let mut repr = FrRepr::deserialize(d)?;
+        let result = Fr::from_repr(repr).map_err(|_| D::Error::custom(ERR_CODE));
+        repr.0.zeroize();
+        result
     }
 }
 
 impl Serialize for FrRepr {
     fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
-        self.0.serialize(s)
+        let bytes = repr_to_be_bytes(&self.0);
+        if s.is_human_readable() {
+            s.serialize_str(&encode_hex(&bytes))
+        } else {
+            serialize_fixed_bytes(&bytes, s)
+        }
     }
 }
 
 impl<'de> Deserialize<'de> for FrRepr {
     fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
-        Ok(FrRepr(<_>::deserialize(d)?))
+        let mut repr = FrRepr::default();
+        let mut bytes = if d.is_human_readable() {
+            d.deserialize_str(HexVisitor)?
+        } else {
+            deserialize_fixed_bytes(d, repr.0.len() * 8)?
+        };
+        let result = repr_from_be_bytes(&bytes, repr.as_mut()).map_err(D::Error::custom);
+        bytes.zeroize();
+        result.map(|()| repr)
     }
 }
 
@@ -110,18 +338,224 @@ impl<'de> Deserialize<'de> for Fq {
 
 impl Serialize for FqRepr {
     fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
-        self.0.serialize(s)
+        let bytes = repr_to_be_bytes(&self.0);
+        if s.is_human_readable() {
+            s.serialize_str(&encode_hex(&bytes))
+        } else {
+            serialize_fixed_bytes(&bytes, s)
+        }
     }
 }
 
 impl<'de> Deserialize<'de> for FqRepr {
     fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
-        Ok(FqRepr(<_>::deserialize(d)?))
+        let mut repr = FqRepr::default();
+        let bytes = if d.is_human_readable() {
+            d.deserialize_str(HexVisitor)?
+        } else {
+            deserialize_fixed_bytes(d, repr.0.len() * 8)?
+        };
+        repr_from_be_bytes(&bytes, repr.as_mut()).map_err(D::Error::custom)?;
+        Ok(repr)
+    }
+}
+
+/// Converts a field representation's little-endian limbs into their canonical big-endian byte
+/// encoding.
+fn repr_to_be_bytes(limbs: &[u64]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(limbs.len() * 8);
+    for limb in limbs.iter().rev() {
+        bytes.extend_from_slice(&limb.to_be_bytes());
+    }
+    bytes
+}
+
+/// Fills `limbs` (little-endian) from a canonical big-endian byte encoding.
+fn repr_from_be_bytes(bytes: &[u8], limbs: &mut [u64]) -> Result<(), &'static str> {
+    if bytes.len() != limbs.len() * 8 {
+        return Err(ERR_LEN);
+    }
+    for (limb, chunk) in limbs.iter_mut().rev().zip(bytes.chunks(8)) {
+        let mut buf = [0u8; 8];
+        buf.copy_from_slice(chunk);
+        *limb = u64::from_be_bytes(buf);
+    }
+    Ok(())
+}
+
+/// Serializes `bytes` as a fixed-size tuple rather than `serialize_bytes`: a repr's byte length
+/// is known at the type level (unlike a `Vec<u8>`), so a binary format that writes no length for
+/// a statically-sized tuple (as it already didn't for the old `[u64; N]`) emits exactly `bytes`,
+/// with no length prefix to "drop" in the first place.
+fn serialize_fixed_bytes<S: Serializer>(bytes: &[u8], s: S) -> Result<S::Ok, S::Error> {
+    let mut tup = s.serialize_tuple(bytes.len())?;
+    for b in bytes {
+        tup.serialize_element(b)?;
+    }
+    tup.end()
+}
+
+/// Deserializes the fixed-size byte tuple written by [`serialize_fixed_bytes`].
+fn deserialize_fixed_bytes<'de, D: Deserializer<'de>>(
+    d: D,
+    len: usize,
+) -> Result<Vec<u8>, D::Error> {
+    struct FixedBytesVisitor(usize);
+
+    impl<'de> Visitor<'de> for FixedBytesVisitor {
+        type Value = Vec<u8>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a tuple of {} bytes", self.0)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<u8>, A::Error> {
+            let mut bytes = Vec::with_capacity(self.0);
+            while let Some(b) = seq.next_element()? {
+                bytes.push(b);
+            }
+            Ok(bytes)
+        }
+    }
+
+    d.deserialize_tuple(len, FixedBytesVisitor(len))
+}
+
+/// Returns the number of bytes in `T`'s canonical representation.
+fn repr_byte_len<T: PrimeField>() -> usize {
+    T::Repr::default().as_ref().len() * 8
+}
+
+/// Wraps a single field element so it `(de)serializes` via its canonical byte encoding,
+/// independently of the crate's own `Fr`/`Fq` impls. [`field_vec`] uses the same per-element
+/// encoding to (de)serialize a whole slice as one contiguous blob.
+pub struct FieldWrap<T>(pub T);
+
+impl<T: PrimeField> Serialize for FieldWrap<T> {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        let bytes = repr_to_be_bytes(self.0.into_repr().as_ref());
+        if s.is_human_readable() {
+            s.serialize_str(&encode_hex(&bytes))
+        } else {
+            s.serialize_bytes(&bytes)
+        }
+    }
+}
+
+impl<'de, T: PrimeField> Deserialize<'de> for FieldWrap<T> {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let bytes = if d.is_human_readable() {
+            d.deserialize_str(HexVisitor)?
+        } else {
+            d.deserialize_bytes(BytesVisitor)?
+        };
+        let mut repr = T::Repr::default();
+        repr_from_be_bytes(&bytes, repr.as_mut()).map_err(D::Error::custom)?;
+        T::from_repr(repr)
+            .map(FieldWrap)
+            .map_err(|_| D::Error::custom(ERR_CODE))
+    }
+}
+
+/// `#[serde(with = "projective_vec")]` (de)serializes a slice of group elements as one
+/// contiguous byte blob (compressed-size × len) instead of one sequence entry per element.
+pub mod projective_vec {
+    use super::*;
+
+    pub fn serialize<C, S>(v: &[C], s: S) -> Result<S::Ok, S::Error>
+    where
+        C: CurveProjective,
+        S: Serializer,
+    {
+        let size = <C::Affine as CurveAffine>::Compressed::size();
+        let mut bytes = Vec::with_capacity(v.len() * size);
+        for c in v {
+            bytes.extend_from_slice(c.into_affine().into_compressed().as_ref());
+        }
+        if s.is_human_readable() {
+            s.serialize_str(&encode_hex(&bytes))
+        } else {
+            s.serialize_bytes(&bytes)
+        }
+    }
+
+    pub fn deserialize<'de, C, D>(d: D) -> Result<Vec<C>, D::Error>
+    where
+        C: CurveProjective,
+        D: Deserializer<'de>,
+    {
+        let bytes = if d.is_human_readable() {
+            d.deserialize_str(HexVisitor)?
+        } else {
+            d.deserialize_bytes(BytesVisitor)?
+        };
+        let size = <C::Affine as CurveAffine>::Compressed::size();
+        if bytes.len() % size != 0 {
+            return Err(D::Error::custom(ERR_LEN));
+        }
+        bytes
+            .chunks(size)
+            .map(|chunk| {
+                let mut compressed = <C::Affine as CurveAffine>::Compressed::empty();
+                compressed.as_mut().copy_from_slice(chunk);
+                compressed
+                    .into_affine()
+                    .map(CurveAffine::into_projective)
+                    .map_err(|_| D::Error::custom(ERR_CODE))
+            })
+            .collect()
+    }
+}
+
+/// `#[serde(with = "field_vec")]` (de)serializes a slice of field elements as one contiguous
+/// byte blob (canonical-size × len) instead of one sequence entry per element.
+pub mod field_vec {
+    use super::*;
+
+    pub fn serialize<T, S>(v: &[T], s: S) -> Result<S::Ok, S::Error>
+    where
+        T: PrimeField,
+        S: Serializer,
+    {
+        let mut bytes = Vec::with_capacity(v.len() * repr_byte_len::<T>());
+        for f in v {
+            bytes.extend_from_slice(&repr_to_be_bytes(f.into_repr().as_ref()));
+        }
+        if s.is_human_readable() {
+            s.serialize_str(&encode_hex(&bytes))
+        } else {
+            s.serialize_bytes(&bytes)
+        }
+    }
+
+    pub fn deserialize<'de, T, D>(d: D) -> Result<Vec<T>, D::Error>
+    where
+        T: PrimeField,
+        D: Deserializer<'de>,
+    {
+        let bytes = if d.is_human_readable() {
+            d.deserialize_str(HexVisitor)?
+        } else {
+            d.deserialize_bytes(BytesVisitor)?
+        };
+        let size = repr_byte_len::<T>();
+        if bytes.len() % size != 0 {
+            return Err(D::Error::custom(ERR_LEN));
+        }
+        bytes
+            .chunks(size)
+            .map(|chunk| {
+                let mut repr = T::Repr::default();
+                repr_from_be_bytes(chunk, repr.as_mut()).map_err(D::Error::custom)?;
+                T::from_repr(repr).map_err(|_| D::Error::custom(ERR_CODE))
+            })
+            .collect()
     }
 }
 
 #[cfg(test)]
 mod tests {
+    extern crate bincode;
     extern crate serde_json;
 
     use super::*;
@@ -135,6 +569,13 @@ mod tests {
         assert_eq!(*t, serde_json::from_slice(&ser).unwrap());
     }
 
+    /// Like `test_roundtrip`, but via `bincode`: a non-human-readable format, so this runs the
+    /// `serialize_bytes`/`deserialize_bytes` (and fixed-tuple) binary paths that JSON never reaches.
+    fn test_roundtrip_binary<T: Serialize + for<'a> Deserialize<'a> + Debug + PartialEq>(t: &T) {
+        let ser = bincode::serialize(t).unwrap();
+        assert_eq!(*t, bincode::deserialize(&ser).unwrap());
+    }
+
     #[test]
     fn serde_g1() {
         let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
@@ -143,6 +584,38 @@ mod tests {
         test_roundtrip(&g.into_affine());
     }
 
+    #[test]
+    fn serde_g1_binary() {
+        let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+        let g: G1 = rng.gen();
+        test_roundtrip_binary(&g.into_affine());
+    }
+
+    #[test]
+    fn repr_be_bytes_roundtrip() {
+        let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+        let f: Fr = rng.gen();
+        let repr = f.into_repr();
+        let bytes = repr_to_be_bytes(&repr.0);
+        assert_eq!(bytes.len(), repr.0.len() * 8);
+        let mut limbs = [0u64; 4];
+        repr_from_be_bytes(&bytes, &mut limbs).unwrap();
+        assert_eq!(limbs, repr.0);
+    }
+
+    #[test]
+    fn serde_g1_json_is_hex_string() {
+        let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+        let g: G1 = rng.gen();
+        let json = serde_json::to_string(&g.into_affine()).unwrap();
+        let hex = json.trim_matches('"');
+        assert_eq!(json.len(), hex.len() + 2);
+        assert!(hex
+            .chars()
+            .all(|c| c.is_ascii_hexdigit() && !c.is_ascii_uppercase()));
+        assert_eq!(hex.len(), 2 * <G1Affine as CurveAffine>::Compressed::size());
+    }
+
     #[test]
     fn serde_g2() {
         let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
@@ -151,6 +624,70 @@ mod tests {
         test_roundtrip(&g.into_affine());
     }
 
+    #[test]
+    fn serde_uncompressed_g1() {
+        let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+        let g: G1 = rng.gen();
+        test_roundtrip(&Uncompressed(g));
+        test_roundtrip(&Uncompressed(g.into_affine()));
+    }
+
+    #[test]
+    fn serde_uncompressed_g2() {
+        let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+        let g: G2 = rng.gen();
+        test_roundtrip(&Uncompressed(g));
+        test_roundtrip(&Uncompressed(g.into_affine()));
+    }
+
+    #[test]
+    fn uncompressed_g1_size() {
+        let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+        let g: G1 = rng.gen();
+        let json = serde_json::to_string(&Uncompressed(g.into_affine())).unwrap();
+        let hex = json.trim_matches('"');
+        assert_eq!(
+            hex.len(),
+            2 * <G1Affine as CurveAffine>::Uncompressed::size()
+        );
+    }
+
+    #[test]
+    fn uncompressed_g1_infinity_is_all_zero_but_flag_bits() {
+        let uncompressed = G1Affine::zero().into_uncompressed();
+        let bytes = uncompressed.as_ref();
+        assert_eq!(bytes[0] & 0x80, 0); // compression flag unset
+        assert_eq!(bytes[0] & 0x40, 0x40); // infinity flag set
+        assert_eq!(bytes[0] & 0x20, 0); // sort flag unset
+        assert_eq!(bytes[0] & 0x1f, 0);
+        assert!(bytes[1..].iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn uncompressed_g1_matches_known_generator_vector() {
+        // The standard BLS12-381 G1 generator, as fixed across BLS12-381 implementations (e.g.
+        // the IETF BLS signature draft and the Zcash protocol spec). Decoding this hardcoded
+        // vector and comparing against the crate's own generator is an actual interop check;
+        // roundtripping a value through our own (de)serializer, as the other tests do, isn't.
+        let hex = "17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac5\
+                   86c55e83ff97a1aeffb3af00adb22c6bb\
+                   08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3e\
+                   dd03cc744a2888ae40caa232946c5e7e1";
+        let json = format!("\"{}\"", hex);
+        let Uncompressed(g): Uncompressed<G1Affine> = serde_json::from_str(&json).unwrap();
+        assert_eq!(g, G1::one().into_affine());
+    }
+
+    #[test]
+    fn uncompressed_rejects_sort_flag_set() {
+        let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+        let g: G1 = rng.gen();
+        let mut uncompressed = g.into_affine().into_uncompressed();
+        uncompressed.as_mut()[0] |= 0x20;
+        let json = format!("\"{}\"", encode_hex(uncompressed.as_ref()));
+        assert!(serde_json::from_str::<Uncompressed<G1Affine>>(&json).is_err());
+    }
+
     #[test]
     fn serde_fr() {
         let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
@@ -159,6 +696,57 @@ mod tests {
         test_roundtrip(&f.into_repr());
     }
 
+    #[test]
+    fn serde_fr_binary() {
+        let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+        let f: Fr = rng.gen();
+        test_roundtrip_binary(&f);
+        test_roundtrip_binary(&f.into_repr());
+    }
+
+    #[test]
+    fn fr_repr_binary_has_no_length_prefix() {
+        // The whole point of using a fixed-size tuple instead of `serialize_bytes` for the repr
+        // types: a bincode encoding of exactly the canonical byte length, with nothing extra.
+        let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+        let f: Fr = rng.gen();
+        let ser = bincode::serialize(&f.into_repr()).unwrap();
+        assert_eq!(ser.len(), 32);
+    }
+
+    #[test]
+    fn serde_secret_fr_roundtrip() {
+        let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+        let f: Fr = rng.gen();
+        let ser = serde_json::to_vec(&SerdeSecret(f)).unwrap();
+        let SerdeSecret(back): SerdeSecret<Fr> = serde_json::from_slice(&ser).unwrap();
+        assert_eq!(f, back);
+    }
+
+    #[test]
+    fn zeroize_is_not_optimized_out() {
+        // `Zeroize::zeroize` (unlike a plain store to a dead local) uses a volatile write, so
+        // this is guaranteed to actually clear the buffer rather than being eliminated by the
+        // optimizer as a dead store to values that are never read again.
+        let mut bytes = vec![0xffu8; 32];
+        bytes.zeroize();
+        assert!(bytes.iter().all(|&b| b == 0));
+
+        let mut limbs = [0xffff_ffff_ffff_ffffu64; 4];
+        limbs.zeroize();
+        assert_eq!(limbs, [0u64; 4]);
+    }
+
+    #[test]
+    fn fr_deserialize_errors_on_out_of_range_repr() {
+        // An all-ones repr is out of range for Fr, so `from_repr` fails; the `Deserialize` impl
+        // above still runs its `zeroize()` calls on this path (see `zeroize_is_not_optimized_out`
+        // for a check that those calls actually clear their target).
+        let bytes = vec![0xffu8; 32];
+        let json = format!("\"{}\"", encode_hex(&bytes));
+        assert!(serde_json::from_str::<Fr>(&json).is_err());
+    }
+
     #[test]
     fn serde_fq() {
         let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
@@ -166,4 +754,81 @@ mod tests {
         test_roundtrip(&f);
         test_roundtrip(&f.into_repr());
     }
+
+    #[test]
+    fn serde_field_wrap() {
+        let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+        let f: Fr = rng.gen();
+        let ser = serde_json::to_vec(&FieldWrap(f)).unwrap();
+        let FieldWrap(back): FieldWrap<Fr> = serde_json::from_slice(&ser).unwrap();
+        assert_eq!(f, back);
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct G1Vec(Vec<G1>);
+
+    impl Serialize for G1Vec {
+        fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+            projective_vec::serialize(&self.0, s)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for G1Vec {
+        fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+            Ok(G1Vec(projective_vec::deserialize(d)?))
+        }
+    }
+
+    #[test]
+    fn serde_projective_vec() {
+        let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+        let v: Vec<G1> = (0..3).map(|_| rng.gen()).collect();
+        test_roundtrip(&G1Vec(v));
+    }
+
+    #[test]
+    fn serde_projective_vec_binary() {
+        let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+        let v: Vec<G1> = (0..3).map(|_| rng.gen()).collect();
+        test_roundtrip_binary(&G1Vec(v));
+    }
+
+    #[test]
+    fn projective_vec_rejects_truncated_blob() {
+        let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+        let v: Vec<G1> = (0..2).map(|_| rng.gen()).collect();
+        let mut ser = serde_json::to_string(&G1Vec(v)).unwrap();
+        ser.truncate(ser.len() - 4);
+        ser.push('"');
+        assert!(serde_json::from_str::<G1Vec>(&ser).is_err());
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct FrVec(Vec<Fr>);
+
+    impl Serialize for FrVec {
+        fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+            field_vec::serialize(&self.0, s)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for FrVec {
+        fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+            Ok(FrVec(field_vec::deserialize(d)?))
+        }
+    }
+
+    #[test]
+    fn serde_field_vec() {
+        let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+        let v: Vec<Fr> = (0..3).map(|_| rng.gen()).collect();
+        test_roundtrip(&FrVec(v));
+    }
+
+    #[test]
+    fn serde_field_vec_binary() {
+        let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+        let v: Vec<Fr> = (0..3).map(|_| rng.gen()).collect();
+        test_roundtrip_binary(&FrVec(v));
+    }
 }